@@ -0,0 +1,61 @@
+use windows::Win32::NetworkManagement::WiFi::{
+    DOT11_AUTH_ALGORITHM, DOT11_CIPHER_ALGORITHM, DOT11_PHY_TYPE,
+};
+
+/// Human-readable label for a `DOT11_PHY_TYPE` value, complementing the
+/// XML-derived `authEncryption`/`encryption` node with the friendly
+/// names for the numeric PHY/cipher/auth enums the live BSS scan deals
+/// in.
+pub fn phy_type_label(phy_type: DOT11_PHY_TYPE) -> String {
+    match phy_type.0 {
+        0 => "Unknown".to_string(),
+        1 => "FHSS".to_string(),
+        2 => "DSSS".to_string(),
+        3 => "Infrared".to_string(),
+        4 => "802.11a (OFDM)".to_string(),
+        5 => "802.11b (HR-DSSS)".to_string(),
+        6 => "802.11g (ERP)".to_string(),
+        7 => "802.11n (HT)".to_string(),
+        8 => "802.11ac (VHT)".to_string(),
+        9 => "802.11ad (DMG)".to_string(),
+        10 => "802.11ax (HE)".to_string(),
+        11 => "802.11be (EHT)".to_string(),
+        other => format!("Proprietary (0x{other:x})"),
+    }
+}
+
+/// Human-readable label for a `DOT11_AUTH_ALGORITHM` value.
+pub fn auth_algorithm_label(auth: DOT11_AUTH_ALGORITHM) -> &'static str {
+    match auth.0 {
+        1 => "Open",
+        2 => "Shared key",
+        3 => "WPA",
+        4 => "WPA-PSK",
+        5 => "WPA-None",
+        6 => "WPA2",
+        7 => "WPA2-PSK",
+        8 => "WPA3",
+        9 => "WPA3-SAE",
+        10 => "OWE",
+        11 => "WPA3-Enterprise",
+        _ => "Unknown",
+    }
+}
+
+/// Human-readable label for a `DOT11_CIPHER_ALGORITHM` value.
+pub fn cipher_algorithm_label(cipher: DOT11_CIPHER_ALGORITHM) -> &'static str {
+    match cipher.0 {
+        0x00 => "None",
+        0x01 => "WEP40",
+        0x02 => "TKIP",
+        0x04 => "CCMP/AES",
+        0x05 => "WEP104",
+        0x06 => "BIP",
+        0x08 => "GCMP",
+        0x09 => "GCMP-256",
+        0x0a => "CCMP-256",
+        0x100 => "WPA/RSN use-group cipher",
+        0x101 => "WEP",
+        _ => "Unknown",
+    }
+}
@@ -0,0 +1,165 @@
+use serde::Serialize;
+use windows::{
+    core::GUID,
+    Win32::{Foundation::HANDLE, NetworkManagement::WiFi::WLAN_INTERFACE_INFO},
+};
+
+use crate::{
+    get_profile_xml, grab_interface_profiles, load_xml_data, parse_utf16_slice, scan,
+    traverse_xml_tree,
+};
+
+/// One recovered profile, modeled for serialization rather than the
+/// human-readable sentences `main` prints in inspection mode.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileRecord {
+    pub ssid: String,
+    pub bssid: Option<String>,
+    pub auth_type: String,
+    pub encryption: Option<String>,
+    pub key_material: Option<String>,
+    pub hidden: bool,
+}
+
+/// All profiles saved against one wireless interface.
+#[derive(Debug, Clone, Serialize)]
+pub struct InterfaceRecord {
+    pub interface_description: String,
+    pub profiles: Vec<ProfileRecord>,
+}
+
+/// Walks every interface and profile the same way `main`'s inspection
+/// loop does, but builds the serializable tree instead of printing.
+pub fn collect_records(
+    handle: HANDLE,
+    interface_list: &[WLAN_INTERFACE_INFO],
+) -> Vec<InterfaceRecord> {
+    interface_list
+        .iter()
+        .filter_map(|interface_info| {
+            let interface_description =
+                parse_utf16_slice(interface_info.strInterfaceDescription.as_slice())?;
+
+            let visible_networks =
+                scan::scan_visible_networks(handle, &interface_info.InterfaceGuid).unwrap_or_default();
+
+            let wlan_profile_ptr =
+                grab_interface_profiles(handle, &interface_info.InterfaceGuid).ok()?;
+
+            let wlan_profile_list = unsafe {
+                std::slice::from_raw_parts(
+                    (*wlan_profile_ptr).ProfileInfo.as_ptr(),
+                    (*wlan_profile_ptr).dwNumberOfItems as usize,
+                )
+            };
+
+            let profiles = wlan_profile_list
+                .iter()
+                .filter_map(|profile| {
+                    build_profile_record(
+                        handle,
+                        &interface_info.InterfaceGuid,
+                        profile,
+                        &visible_networks,
+                    )
+                })
+                .collect();
+
+            unsafe { windows::Win32::NetworkManagement::WiFi::WlanFreeMemory(wlan_profile_ptr.cast()) };
+
+            Some(InterfaceRecord {
+                interface_description: interface_description.to_string_lossy().to_string(),
+                profiles,
+            })
+        })
+        .collect()
+}
+
+fn build_profile_record(
+    handle: HANDLE,
+    interface_guid: &GUID,
+    profile: &windows::Win32::NetworkManagement::WiFi::WLAN_PROFILE_INFO,
+    visible_networks: &[scan::VisibleNetwork],
+) -> Option<ProfileRecord> {
+    let profile_name = parse_utf16_slice(&profile.strProfileName)?;
+    let ssid = profile_name.to_string_lossy().to_string();
+
+    let profile_xml_data = get_profile_xml(handle, interface_guid, &profile_name).ok()?;
+    let xml_document = load_xml_data(&profile_xml_data).ok()?;
+    let root = xml_document.DocumentElement().ok()?;
+
+    let auth_type = traverse_xml_tree(&root, &["MSM", "security", "authEncryption", "authentication"])?;
+    let encryption = traverse_xml_tree(&root, &["MSM", "security", "authEncryption", "encryption"]);
+    let key_material = traverse_xml_tree(&root, &["MSM", "security", "sharedKey", "keyMaterial"]);
+    let hidden = traverse_xml_tree(&root, &["SSIDConfig", "nonBroadcast"])
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+
+    let bssid = visible_networks
+        .iter()
+        .find(|network| network.ssid == ssid)
+        .map(|network| {
+            network
+                .bssid
+                .iter()
+                .map(|byte| format!("{byte:02X}"))
+                .collect::<Vec<_>>()
+                .join(":")
+        });
+
+    Some(ProfileRecord {
+        ssid,
+        bssid,
+        auth_type,
+        encryption,
+        key_material,
+        hidden,
+    })
+}
+
+/// Replaces `key_material` with a placeholder so the exported tree can
+/// be shared without leaking recovered passwords.
+pub fn redact_passwords(records: &mut [InterfaceRecord]) {
+    for interface in records {
+        for profile in &mut interface.profiles {
+            if profile.key_material.is_some() {
+                profile.key_material = Some("***REDACTED***".to_string());
+            }
+        }
+    }
+}
+
+pub fn to_json(records: &[InterfaceRecord]) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(records)
+}
+
+pub fn to_csv(records: &[InterfaceRecord]) -> Result<String, csv::Error> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    writer.write_record([
+        "interface",
+        "ssid",
+        "bssid",
+        "auth_type",
+        "encryption",
+        "key_material",
+        "hidden",
+    ])?;
+
+    for interface in records {
+        for profile in &interface.profiles {
+            writer.write_record([
+                interface.interface_description.as_str(),
+                profile.ssid.as_str(),
+                profile.bssid.as_deref().unwrap_or(""),
+                profile.auth_type.as_str(),
+                profile.encryption.as_deref().unwrap_or(""),
+                profile.key_material.as_deref().unwrap_or(""),
+                if profile.hidden { "true" } else { "false" },
+            ])?;
+        }
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
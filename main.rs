@@ -1,5 +1,15 @@
 use std::{ffi::OsString, os::windows::ffi::OsStringExt};
 
+mod dot11;
+mod export;
+mod management;
+mod psk;
+mod qr;
+mod scan;
+mod security;
+
+use security::{classify_security, extract_eap_info, SecurityType};
+
 use windows::{
     core::{Interface, GUID, HSTRING, PCWSTR, PWSTR},
     Data::Xml::Dom::{XmlDocument, XmlElement},
@@ -7,7 +17,7 @@ use windows::{
         Foundation::{HANDLE, WIN32_ERROR, INVALID_HANDLE_VALUE},
         NetworkManagement::WiFi::{
             WlanCloseHandle, WlanEnumInterfaces, WlanOpenHandle, WlanGetProfile,
-            WlanGetProfileList, WlanFreeMemory, WLAN_INTERFACE_INFO_LIST,
+            WlanGetProfileList, WlanFreeMemory, WLAN_INTERFACE_INFO, WLAN_INTERFACE_INFO_LIST,
             WLAN_PROFILE_INFO_LIST, WLAN_PROFILE_GET_PLAINTEXT_KEY, WLAN_API_VERSION_2_0,
         },
     },
@@ -142,6 +152,108 @@ fn get_profile_xml(
 }
 
 
+/// Finds the interface whose GUID or description matches `selector`,
+/// so management commands aren't stuck targeting whichever interface
+/// happens to enumerate first.
+fn find_interface<'a>(
+    interface_list: &'a [WLAN_INTERFACE_INFO],
+    selector: &str,
+) -> Option<&'a WLAN_INTERFACE_INFO> {
+    let selector = selector.trim_matches(|c| c == '{' || c == '}');
+
+    interface_list.iter().find(|interface_info| {
+        let guid_matches = format!("{:?}", interface_info.InterfaceGuid)
+            .trim_matches(|c| c == '{' || c == '}')
+            .eq_ignore_ascii_case(selector);
+
+        let description_matches = parse_utf16_slice(interface_info.strInterfaceDescription.as_slice())
+            .map(|description| description.to_string_lossy().eq_ignore_ascii_case(selector))
+            .unwrap_or(false);
+
+        guid_matches || description_matches
+    })
+}
+
+/// Dispatches the `create`/`import`/`delete`/`connect`/`disconnect`
+/// profile management subcommands against `interface_guid`. This is the
+/// CLI entry point for turning the visualizer into a saved-network
+/// administration tool, sitting next to the read-only inspection that
+/// runs when no subcommand is given. Callers pick the target interface
+/// (e.g. via a `--interface <guid-or-description>` argument) rather than
+/// this function assuming one.
+fn run_management_command(handle: HANDLE, interface_guid: &GUID, args: &[String]) {
+    let result = match args {
+        [cmd, ssid, passphrase, auth_type] if cmd == "create" => {
+            management::build_profile_document(ssid, Some(passphrase), auth_type)
+                .and_then(|doc| management::set_profile(handle, interface_guid, &doc))
+        }
+        [cmd, ssid, auth_type] if cmd == "create" => {
+            management::build_profile_document(ssid, None, auth_type)
+                .and_then(|doc| management::set_profile(handle, interface_guid, &doc))
+        }
+        [cmd, path] if cmd == "import" => management::load_profile_document_from_file(
+            std::path::Path::new(path),
+        )
+        .and_then(|doc| management::set_profile(handle, interface_guid, &doc)),
+        [cmd, profile_name] if cmd == "delete" => {
+            management::delete_profile(handle, interface_guid, profile_name)
+        }
+        [cmd, profile_name] if cmd == "connect" => {
+            management::connect_profile(handle, interface_guid, profile_name)
+        }
+        [cmd] if cmd == "disconnect" => management::disconnect(handle, interface_guid),
+        _ => {
+            eprintln!(
+                "Unrecognized command. Usage: [--interface <guid-or-description>] create <ssid> [passphrase] <auth_type> | import <path> | delete <profile> | connect <profile> | disconnect"
+            );
+            return;
+        }
+    };
+
+    match result {
+        Ok(()) => println!("Command completed successfully"),
+        Err(e) => eprintln!("Command failed: {:?}", e),
+    }
+}
+
+/// Handles `export <json|csv> [--redact]`: builds the interface/profile
+/// tree and prints it in the requested format instead of the
+/// human-readable sentences the default inspection mode prints.
+fn run_export_command(handle: HANDLE, interface_list: &[WLAN_INTERFACE_INFO], args: &[String]) {
+    let mut records = export::collect_records(handle, interface_list);
+
+    if args.iter().any(|arg| arg == "--redact") {
+        export::redact_passwords(&mut records);
+    }
+
+    match args.first().map(String::as_str) {
+        Some("json") => match export::to_json(&records) {
+            Ok(json) => println!("{json}"),
+            Err(e) => eprintln!("Failed to serialize records as JSON: {:?}", e),
+        },
+        Some("csv") => match export::to_csv(&records) {
+            Ok(csv) => print!("{csv}"),
+            Err(e) => eprintln!("Failed to serialize records as CSV: {:?}", e),
+        },
+        _ => eprintln!("Usage: export <json|csv> [--redact]"),
+    }
+}
+
+/// Prints a Wi-Fi join QR to the terminal and, when `png_dir` is set,
+/// also writes it as a PNG named after the SSID.
+fn emit_qr(payload: &str, ssid: &str, png_dir: Option<&std::path::Path>) {
+    if let Err(e) = qr::print_qr_to_terminal(payload) {
+        eprintln!("Failed to render QR code: {:?}", e);
+    }
+
+    if let Some(dir) = png_dir {
+        let path = dir.join(qr::qr_png_filename(ssid));
+        if let Err(e) = qr::write_qr_png(payload, &path) {
+            eprintln!("Failed to write QR PNG: {:?}", e);
+        }
+    }
+}
+
 fn main() {
     let wlan_handle = open_wlan_handle(WLAN_API_VERSION_2_0).expect("Failed to open WLAN handle");
 
@@ -161,6 +273,44 @@ fn main() {
         )
     };
 
+    let args: Vec<String> = std::env::args().collect();
+    let qr_png_dir: Option<std::path::PathBuf> = if args.get(1).map(String::as_str) == Some("--png") {
+        Some(std::path::PathBuf::from(args.get(2).expect("--png requires a directory argument")))
+    } else {
+        None
+    };
+
+    if args.get(1).map(String::as_str) == Some("export") {
+        run_export_command(wlan_handle, interface_list, &args[2..]);
+        unsafe { WlanFreeMemory(interface_ptr.cast()) };
+        unsafe { WlanCloseHandle(wlan_handle, None) };
+        return;
+    } else if qr_png_dir.is_none() && args.len() > 1 {
+        let (selector, command_args) = if args.get(1).map(String::as_str) == Some("--interface") {
+            (args.get(2).map(String::as_str), &args[3.min(args.len())..])
+        } else {
+            (None, &args[1..])
+        };
+
+        let target_interface = match selector {
+            Some(selector) => match find_interface(interface_list, selector) {
+                Some(interface) => interface,
+                None => {
+                    eprintln!("No interface matching \"{selector}\" was found");
+                    unsafe { WlanFreeMemory(interface_ptr.cast()) };
+                    unsafe { WlanCloseHandle(wlan_handle, None) };
+                    return;
+                }
+            },
+            None => interface_list.first().expect("No wireless interfaces found"),
+        };
+
+        run_management_command(wlan_handle, &target_interface.InterfaceGuid, command_args);
+        unsafe { WlanFreeMemory(interface_ptr.cast()) };
+        unsafe { WlanCloseHandle(wlan_handle, None) };
+        return;
+    }
+
     for interface_info in interface_list {
         let interface_description = match parse_utf16_slice(interface_info.strInterfaceDescription.as_slice()) {
             Some(name) => name,
@@ -170,6 +320,26 @@ fn main() {
             }
         };
 
+        match scan::scan_visible_networks(wlan_handle, &interface_info.InterfaceGuid) {
+            Ok(networks) => {
+                println!("Visible networks on {}:", interface_description.to_string_lossy());
+                for network in &networks {
+                    println!(
+                        "  SSID: {}, BSSID: {:02X?}, RSSI: {} dBm, Link quality: {}%, Frequency: {} kHz, PHY: {}, Auth: {}, Cipher: {}",
+                        network.ssid,
+                        network.bssid,
+                        network.rssi_dbm,
+                        network.link_quality,
+                        network.channel_center_frequency_khz,
+                        network.phy_type,
+                        network.auth_algorithm.as_deref().unwrap_or("unknown"),
+                        network.cipher_algorithm.as_deref().unwrap_or("unknown"),
+                    );
+                }
+            }
+            Err(e) => eprintln!("Failed to scan for visible networks: {:?}", e),
+        }
+
         let wlan_profile_ptr = match grab_interface_profiles(wlan_handle, &interface_info.InterfaceGuid) {
             Ok(profiles) => profiles,
             Err(_e) => {
@@ -229,23 +399,50 @@ fn main() {
                 }
             };
 
-            match auth_type.as_str() {
-                "open" => {
-                    println!("Wi-Fi name: {}, No password", profile_name.to_string_lossy().to_string());
-                },
-                "WPA2" | "WPA2PSK" => {
-                    if let Some(password) = traverse_xml_tree(&root, &["MSM", "security", "sharedKey", "keyMaterial"]) {
-                        println!("Wi-Fi name: {}, Authentication: {}, Password: {}", 
-                        profile_name.to_string_lossy().to_string(), auth_type, password);
-                    }
-                }
-                _ => {
-                    println!(
-                        "Wi-Fi name: {}, Authentication: {}, Password retrieval not supported", 
-                        profile_name.to_string_lossy().to_string(), 
-                        auth_type
-                    );
+            let ssid = profile_name.to_string_lossy().to_string();
+            let hidden = traverse_xml_tree(&root, &["SSIDConfig", "nonBroadcast"])
+                .map(|value| value.eq_ignore_ascii_case("true"))
+                .unwrap_or(false);
+
+            let encryption = traverse_xml_tree(&root, &["MSM", "security", "authEncryption", "encryption"])
+                .unwrap_or_default();
+            let security = classify_security(&auth_type, &encryption);
+            let shared_key = traverse_xml_tree(&root, &["MSM", "security", "sharedKey", "keyMaterial"]);
+
+            if security == SecurityType::Open {
+                println!("Wi-Fi name: {}, No password", ssid);
+                let payload = qr::build_wifi_qr_payload(&ssid, None, security, hidden);
+                emit_qr(&payload, &ssid, qr_png_dir.as_deref());
+            } else if let Some(password) = shared_key.filter(|_| {
+                matches!(
+                    security,
+                    SecurityType::Wep | SecurityType::WpaPsk | SecurityType::Wpa2Psk | SecurityType::Wpa3Sae
+                )
+            }) {
+                println!("Wi-Fi name: {}, Authentication: {}, Password: {}",
+                ssid, auth_type, password);
+
+                if matches!(security, SecurityType::WpaPsk | SecurityType::Wpa2Psk) {
+                    println!("  Raw PSK: {}", psk::resolve_psk(&password, &ssid));
                 }
+
+                let payload = qr::build_wifi_qr_payload(&ssid, Some(&password), security, hidden);
+                emit_qr(&payload, &ssid, qr_png_dir.as_deref());
+            } else if security == SecurityType::Enterprise {
+                let eap = extract_eap_info(&root);
+                println!(
+                    "Wi-Fi name: {}, Authentication: {}, Enterprise profile - EAP method: {}, Identity: {}",
+                    ssid,
+                    auth_type,
+                    eap.method_type.as_deref().unwrap_or("unknown"),
+                    eap.identity.as_deref().unwrap_or("not stored in profile"),
+                );
+            } else {
+                println!(
+                    "Wi-Fi name: {}, Authentication: {}, Password retrieval not supported",
+                    ssid,
+                    auth_type
+                );
             }
         }
     }
@@ -0,0 +1,165 @@
+use windows::{
+    core::{GUID, HSTRING, PCWSTR},
+    Data::Xml::Dom::XmlDocument,
+    Win32::{
+        Foundation::{HANDLE, WIN32_ERROR},
+        NetworkManagement::WiFi::{
+            WlanConnect, WlanDeleteProfile, WlanDisconnect, WlanSetProfile,
+            DOT11_BSS_TYPE_INFRASTRUCTURE, WLAN_CONNECTION_MODE_PROFILE,
+            WLAN_CONNECTION_PARAMETERS,
+        },
+    },
+};
+
+/// Escapes the five predefined XML entities so a value can be
+/// interpolated into element text without producing invalid XML or
+/// letting a crafted SSID/passphrase inject sibling elements.
+fn escape_xml_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Builds the minimal profile document Windows expects for an open or
+/// WPA2-PSK network, the same shape `WlanGetProfile` hands back for an
+/// existing profile. Loaded through `XmlDocument` like the rest of the
+/// profile XML handling in this crate, so it gets the same validation.
+pub fn build_profile_document(
+    ssid: &str,
+    passphrase: Option<&str>,
+    auth_type: &str,
+) -> Result<XmlDocument, windows::core::Error> {
+    let ssid = escape_xml_text(ssid);
+    let auth_type = escape_xml_text(auth_type);
+    let encryption = if passphrase.is_some() { "AES" } else { "none" };
+    let security_block = match passphrase {
+        Some(pass) => format!(
+            "<sharedKey><keyType>passPhrase</keyType><protected>false</protected><keyMaterial>{}</keyMaterial></sharedKey>",
+            escape_xml_text(pass)
+        ),
+        None => String::new(),
+    };
+
+    let xml = format!(
+        r#"<?xml version="1.0"?>
+<WLANProfile xmlns="http://www.microsoft.com/networking/WLAN/profile/v1">
+    <name>{ssid}</name>
+    <SSIDConfig>
+        <SSID>
+            <name>{ssid}</name>
+        </SSID>
+    </SSIDConfig>
+    <connectionType>ESS</connectionType>
+    <connectionMode>auto</connectionMode>
+    <MSM>
+        <security>
+            <authEncryption>
+                <authentication>{auth_type}</authentication>
+                <encryption>{encryption}</encryption>
+                <useOneX>false</useOneX>
+            </authEncryption>
+            {security_block}
+        </security>
+    </MSM>
+</WLANProfile>"#
+    );
+
+    let document = XmlDocument::new()?;
+    document.LoadXml(&HSTRING::from(&xml))?;
+    Ok(document)
+}
+
+/// Loads a profile XML document from a file on disk, for importing a
+/// profile exported elsewhere (e.g. via `netsh wlan export profile`).
+pub fn load_profile_document_from_file(
+    path: &std::path::Path,
+) -> Result<XmlDocument, windows::core::Error> {
+    let xml = std::fs::read_to_string(path).map_err(|e| {
+        windows::core::Error::new(windows::core::HRESULT(e.raw_os_error().unwrap_or(-1)), "")
+    })?;
+
+    let document = XmlDocument::new()?;
+    document.LoadXml(&HSTRING::from(&xml))?;
+    Ok(document)
+}
+
+/// Installs (creates or overwrites) a profile on `interface_guid` from
+/// an already-built profile document.
+pub fn set_profile(
+    handle: HANDLE,
+    interface_guid: &GUID,
+    profile_document: &XmlDocument,
+) -> Result<(), windows::core::Error> {
+    let profile_xml = profile_document.GetXml()?;
+    let mut reason_code: u32 = 0;
+
+    let result = unsafe {
+        WlanSetProfile(
+            handle,
+            interface_guid,
+            0,
+            PCWSTR(profile_xml.as_ptr()),
+            None,
+            true,
+            None,
+            &mut reason_code,
+        )
+    };
+
+    WIN32_ERROR(result).ok()
+}
+
+/// Removes a saved profile by name from `interface_guid`.
+pub fn delete_profile(
+    handle: HANDLE,
+    interface_guid: &GUID,
+    profile_name: &str,
+) -> Result<(), windows::core::Error> {
+    let result = unsafe {
+        WlanDeleteProfile(
+            handle,
+            interface_guid,
+            PCWSTR(HSTRING::from(profile_name).as_ptr()),
+            None,
+        )
+    };
+
+    WIN32_ERROR(result).ok()
+}
+
+/// Joins `interface_guid` to the named, already-saved profile.
+pub fn connect_profile(
+    handle: HANDLE,
+    interface_guid: &GUID,
+    profile_name: &str,
+) -> Result<(), windows::core::Error> {
+    let profile_name_hstring = HSTRING::from(profile_name);
+
+    let connection_params = WLAN_CONNECTION_PARAMETERS {
+        wlanConnectionMode: WLAN_CONNECTION_MODE_PROFILE,
+        strProfile: PCWSTR(profile_name_hstring.as_ptr()),
+        pDot11Ssid: std::ptr::null(),
+        pDesiredBssidList: std::ptr::null(),
+        dot11BssType: DOT11_BSS_TYPE_INFRASTRUCTURE,
+        dwFlags: 0,
+    };
+
+    let result = unsafe { WlanConnect(handle, interface_guid, &connection_params) };
+
+    WIN32_ERROR(result).ok()
+}
+
+/// Drops the active connection on `interface_guid`, if any.
+pub fn disconnect(handle: HANDLE, interface_guid: &GUID) -> Result<(), windows::core::Error> {
+    let result = unsafe { WlanDisconnect(handle, interface_guid, None) };
+    WIN32_ERROR(result).ok()
+}
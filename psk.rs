@@ -0,0 +1,28 @@
+use pbkdf2::pbkdf2_hmac;
+use sha1::Sha1;
+
+/// Derives the raw 256-bit WPA/WPA2-PSK from a passphrase and SSID per
+/// IEEE 802.11i: PBKDF2-HMAC-SHA1 with the passphrase as the password,
+/// the SSID bytes as the salt, 4096 iterations, and a 32-byte output.
+pub fn derive_psk(passphrase: &str, ssid: &str) -> String {
+    let mut psk = [0u8; 32];
+    pbkdf2_hmac::<Sha1>(passphrase.as_bytes(), ssid.as_bytes(), 4096, &mut psk);
+    psk.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Whether `key_material` already looks like a 64-hex-digit raw PSK
+/// rather than an 8-63 character ASCII passphrase.
+fn looks_like_raw_psk(key_material: &str) -> bool {
+    key_material.len() == 64 && key_material.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Resolves a profile's `keyMaterial` to the raw 256-bit PSK: if it's
+/// already 64 hex digits it IS the PSK, otherwise it's treated as a
+/// passphrase and derived with [`derive_psk`].
+pub fn resolve_psk(key_material: &str, ssid: &str) -> String {
+    if looks_like_raw_psk(key_material) {
+        key_material.to_lowercase()
+    } else {
+        derive_psk(key_material, ssid)
+    }
+}
@@ -0,0 +1,81 @@
+use qrcode::{render::unicode, QrCode};
+
+use crate::security::SecurityType;
+
+/// Escapes the characters the Wi-Fi QR payload format treats as
+/// delimiters (`\ ; , : "`) with a backslash, per the spec phones use
+/// when scanning a `WIFI:` join code.
+fn escape_wifi_field(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        if matches!(c, '\\' | ';' | ',' | ':' | '"') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Maps a classified `SecurityType` to the `T:` token the Wi-Fi QR format
+/// expects. Takes the already-classified type rather than the raw
+/// `authentication` string: WEP profiles are classified from the
+/// `encryption` node and still carry `authentication` values of `open` or
+/// `shared`, so deriving the token from `authentication` alone would
+/// mislabel a WEP network as `nopass`/`WPA`.
+fn auth_type_to_qr_token(security: SecurityType) -> &'static str {
+    match security {
+        SecurityType::Open => "nopass",
+        SecurityType::Wep => "WEP",
+        _ => "WPA",
+    }
+}
+
+/// Builds the standard `WIFI:S:<ssid>;T:<auth>;P:<password>;H:<hidden>;;`
+/// payload that phone cameras recognize as a network join code.
+pub fn build_wifi_qr_payload(
+    ssid: &str,
+    password: Option<&str>,
+    security: SecurityType,
+    hidden: bool,
+) -> String {
+    format!(
+        "WIFI:S:{};T:{};P:{};H:{};;",
+        escape_wifi_field(ssid),
+        auth_type_to_qr_token(security),
+        escape_wifi_field(password.unwrap_or("")),
+        hidden,
+    )
+}
+
+/// Renders a Wi-Fi join payload as a Unicode block QR code straight to
+/// the terminal.
+pub fn print_qr_to_terminal(payload: &str) -> Result<(), qrcode::types::QrError> {
+    let code = QrCode::new(payload.as_bytes())?;
+    let image = code
+        .render::<unicode::Dense1x2>()
+        .quiet_zone(false)
+        .build();
+    println!("{image}");
+    Ok(())
+}
+
+/// Renders a Wi-Fi join payload to a PNG file on disk.
+pub fn write_qr_png(payload: &str, path: &std::path::Path) -> Result<(), qrcode::types::QrError> {
+    let code = QrCode::new(payload.as_bytes())?;
+    let image = code.render::<image::Luma<u8>>().build();
+    image
+        .save(path)
+        .unwrap_or_else(|e| eprintln!("Failed to write QR PNG {}: {:?}", path.display(), e));
+    Ok(())
+}
+
+/// Turns an SSID into a safe PNG filename by replacing every character
+/// other than alphanumerics, `-`, `_` and `.` (path separators and spaces
+/// included) with `_`.
+pub fn qr_png_filename(ssid: &str) -> String {
+    let sanitized: String = ssid
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+        .collect();
+    format!("{sanitized}.png")
+}
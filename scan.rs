@@ -0,0 +1,158 @@
+use std::time::{Duration, Instant};
+
+use windows::{
+    core::GUID,
+    Win32::{
+        Foundation::{HANDLE, WIN32_ERROR},
+        NetworkManagement::WiFi::{
+            WlanFreeMemory, WlanGetAvailableNetworkList, WlanGetNetworkBssList, WlanScan,
+            DOT11_BSS_TYPE_INFRASTRUCTURE, WLAN_AVAILABLE_NETWORK_LIST, WLAN_BSS_LIST,
+        },
+    },
+};
+
+use crate::dot11::{auth_algorithm_label, cipher_algorithm_label, phy_type_label};
+
+/// How long to keep polling for `WlanScan` to populate the BSS list
+/// before giving up and using whatever (possibly stale/empty) list is
+/// currently cached.
+const SCAN_POLL_TIMEOUT: Duration = Duration::from_secs(4);
+const SCAN_POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// A single access point observed in range of an interface, built from a
+/// `WLAN_BSS_ENTRY` returned by `WlanGetNetworkBssList`, enriched with
+/// the default auth/cipher Windows reports for that SSID in the
+/// available-network list.
+#[derive(Debug, Clone)]
+pub struct VisibleNetwork {
+    pub ssid: String,
+    pub bssid: [u8; 6],
+    pub rssi_dbm: i32,
+    pub link_quality: u32,
+    pub channel_center_frequency_khz: u32,
+    pub phy_type: String,
+    pub auth_algorithm: Option<String>,
+    pub cipher_algorithm: Option<String>,
+}
+
+/// Maps each SSID Windows currently sees to the default auth/cipher
+/// algorithm it reports for it, pulled from `WlanGetAvailableNetworkList`.
+fn available_network_security(
+    handle: HANDLE,
+    interface_guid: &GUID,
+) -> Result<std::collections::HashMap<String, (String, String)>, windows::core::Error> {
+    let mut network_list_ptr: *mut WLAN_AVAILABLE_NETWORK_LIST = std::ptr::null_mut();
+
+    let result = unsafe {
+        WlanGetAvailableNetworkList(handle, interface_guid, 0, None, &mut network_list_ptr)
+    };
+
+    WIN32_ERROR(result).ok()?;
+
+    let networks = unsafe {
+        std::slice::from_raw_parts(
+            (*network_list_ptr).Network.as_ptr(),
+            (*network_list_ptr).dwNumberOfItems as usize,
+        )
+    };
+
+    let security_by_ssid = networks
+        .iter()
+        .map(|network| {
+            let ssid_len = (network.dot11Ssid.uSSIDLength as usize).min(network.dot11Ssid.ucSSID.len());
+            let ssid = String::from_utf8_lossy(&network.dot11Ssid.ucSSID[..ssid_len]).into_owned();
+            (
+                ssid,
+                (
+                    auth_algorithm_label(network.dot11DefaultAuthAlgorithm).to_string(),
+                    cipher_algorithm_label(network.dot11DefaultCipherAlgorithm).to_string(),
+                ),
+            )
+        })
+        .collect();
+
+    unsafe { WlanFreeMemory(network_list_ptr.cast()) };
+
+    Ok(security_by_ssid)
+}
+
+/// Pulls the BSS list currently cached by the driver for `interface_guid`.
+/// A list pulled via a `*mut` out-param and freed with `WlanFreeMemory`,
+/// mirroring `grab_interface_profiles`.
+fn get_bss_list(
+    handle: HANDLE,
+    interface_guid: &GUID,
+) -> Result<*mut WLAN_BSS_LIST, windows::core::Error> {
+    let mut bss_list_ptr: *mut WLAN_BSS_LIST = std::ptr::null_mut();
+
+    let result = unsafe {
+        WlanGetNetworkBssList(
+            handle,
+            interface_guid,
+            None,
+            DOT11_BSS_TYPE_INFRASTRUCTURE,
+            false,
+            None,
+            &mut bss_list_ptr,
+        )
+    };
+
+    WIN32_ERROR(result).ok()?;
+
+    Ok(bss_list_ptr)
+}
+
+/// Asks the driver to (re)scan and then reads back the resulting BSS list
+/// for `interface_guid`. `WlanScan` only requests a scan and returns
+/// immediately, so reading the BSS list right away would typically see
+/// the list from before the scan was requested. Poll until the list is
+/// non-empty or `SCAN_POLL_TIMEOUT` elapses, then use whatever's cached.
+pub fn scan_visible_networks(
+    handle: HANDLE,
+    interface_guid: &GUID,
+) -> Result<Vec<VisibleNetwork>, windows::core::Error> {
+    let scan_result = unsafe { WlanScan(handle, interface_guid, None, None, None) };
+    WIN32_ERROR(scan_result).ok()?;
+
+    let security_by_ssid = available_network_security(handle, interface_guid).unwrap_or_default();
+
+    let deadline = Instant::now() + SCAN_POLL_TIMEOUT;
+    let mut bss_list_ptr = get_bss_list(handle, interface_guid)?;
+
+    while unsafe { (*bss_list_ptr).dwNumberOfItems } == 0 && Instant::now() < deadline {
+        unsafe { WlanFreeMemory(bss_list_ptr.cast()) };
+        std::thread::sleep(SCAN_POLL_INTERVAL);
+        bss_list_ptr = get_bss_list(handle, interface_guid)?;
+    }
+
+    let bss_entries = unsafe {
+        std::slice::from_raw_parts(
+            (*bss_list_ptr).wlanBssEntries.as_ptr(),
+            (*bss_list_ptr).dwNumberOfItems as usize,
+        )
+    };
+
+    let networks = bss_entries
+        .iter()
+        .map(|entry| {
+            let ssid_len = (entry.dot11Ssid.uSSIDLength as usize).min(entry.dot11Ssid.ucSSID.len());
+            let ssid = String::from_utf8_lossy(&entry.dot11Ssid.ucSSID[..ssid_len]).into_owned();
+            let security = security_by_ssid.get(&ssid);
+
+            VisibleNetwork {
+                bssid: entry.dot11Bssid,
+                rssi_dbm: entry.lRssi,
+                link_quality: entry.uLinkQuality,
+                channel_center_frequency_khz: entry.ulChCenterFrequency,
+                phy_type: phy_type_label(entry.dot11BssPhyType),
+                auth_algorithm: security.map(|(auth, _)| auth.clone()),
+                cipher_algorithm: security.map(|(_, cipher)| cipher.clone()),
+                ssid,
+            }
+        })
+        .collect();
+
+    unsafe { WlanFreeMemory(bss_list_ptr.cast()) };
+
+    Ok(networks)
+}
@@ -0,0 +1,119 @@
+use windows::Data::Xml::Dom::XmlElement;
+
+use crate::traverse_xml_tree;
+
+/// Which Wi-Fi security scheme a profile uses, classified from the
+/// `authentication` and `encryption` values Windows writes under
+/// `MSM/security/authEncryption` (see [`classify_security`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityType {
+    Open,
+    Wep,
+    WpaPsk,
+    Wpa2Psk,
+    Wpa3Sae,
+    /// `WPA`/`WPA2`/`WPA3ENT` with no shared key in the profile: the
+    /// credentials live behind an `<OneX>`/EAP handshake instead.
+    Enterprise,
+    Unknown,
+}
+
+/// Classifies the raw `authentication`/`encryption` node values into a
+/// `SecurityType`. Windows writes WEP profiles as `<authentication>open</authentication>`
+/// or `<authentication>shared</authentication>` with `<encryption>WEP</encryption>`,
+/// not a literal "WEP" authentication value, so `encryption` takes
+/// priority over `auth_type` for that case. Bare `WPA`/`WPA2` (no `PSK`
+/// suffix) are ambiguous on `auth_type` alone, since Windows also writes
+/// them for the 802.1X/enterprise case; callers should fall back to
+/// [`extract_eap_info`] when no shared key is present.
+pub fn classify_security(auth_type: &str, encryption: &str) -> SecurityType {
+    if encryption.eq_ignore_ascii_case("WEP") {
+        return SecurityType::Wep;
+    }
+
+    match auth_type {
+        "open" => SecurityType::Open,
+        "WPAPSK" => SecurityType::WpaPsk,
+        "WPA2PSK" => SecurityType::Wpa2Psk,
+        "WPA3SAE" => SecurityType::Wpa3Sae,
+        "WPA3ENT" => SecurityType::Enterprise,
+        "WPA" | "WPA2" => SecurityType::Enterprise,
+        _ => SecurityType::Unknown,
+    }
+}
+
+/// The EAP method and identity surfaced for an enterprise profile, in
+/// place of a password that simply isn't stored in the profile XML.
+#[derive(Debug, Clone)]
+pub struct EapInfo {
+    pub method_type: Option<String>,
+    pub identity: Option<String>,
+}
+
+/// Walks a fixed path of element names from `node`, returning the
+/// element reached (not its text), so callers can keep navigating past
+/// it. Unlike `traverse_xml_tree`, which only yields the final node's
+/// text, this hands back the node itself.
+fn descend_to_element(node: &XmlElement, path: &[&str]) -> Option<XmlElement> {
+    let mut current = node.clone();
+
+    for name in path {
+        let children = current.ChildNodes().ok()?;
+        let mut next = None;
+
+        for i in 0..children.Length().ok()? {
+            let child = children.Item(i).ok()?;
+            if child.NodeName().ok()?.to_string() == *name {
+                next = child.cast::<XmlElement>().ok();
+                break;
+            }
+        }
+
+        current = next?;
+    }
+
+    Some(current)
+}
+
+/// Searches the whole subtree rooted at `node` for the first descendant
+/// element named `target`, returning its text content.
+fn find_descendant_text(node: &XmlElement, target: &str) -> Option<String> {
+    let children = node.ChildNodes().ok()?;
+
+    for i in 0..children.Length().ok()? {
+        let child = children.Item(i).ok()?;
+
+        if child.NodeName().ok()?.to_string() == target {
+            return child.InnerText().ok().map(|s| s.to_string());
+        }
+
+        if let Ok(element) = child.cast::<XmlElement>() {
+            if let Some(found) = find_descendant_text(&element, target) {
+                return Some(found);
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads whatever `<OneX>`/EAP config is present under `MSM/security` for
+/// an enterprise profile. `Identity` isn't at a fixed depth under
+/// `Config`: it's nested differently per EAP method (e.g.
+/// `Config/Eap/EapType/Credentials/Identity` for PEAP), so rather than
+/// guess a single path, this descends to `Config` and searches its whole
+/// subtree for the first `Identity` node.
+pub fn extract_eap_info(root: &XmlElement) -> EapInfo {
+    let config = descend_to_element(
+        root,
+        &["MSM", "security", "OneX", "EAPConfig", "EapHostConfig", "Config"],
+    );
+
+    EapInfo {
+        method_type: traverse_xml_tree(
+            root,
+            &["MSM", "security", "OneX", "EAPConfig", "EapHostConfig", "EapMethod", "Type"],
+        ),
+        identity: config.and_then(|config| find_descendant_text(&config, "Identity")),
+    }
+}